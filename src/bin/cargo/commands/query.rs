@@ -1,20 +1,35 @@
 use crate::command_prelude::*;
 
 use cargo::{
-    core::{profiles::Profiles, Target, Workspace},
+    core::{profiles::Profiles, Package, Target, Workspace},
     ops::CompileOptions,
     util::{errors::CargoResult, get_available_targets},
 };
 use clap::{ArgEnum, PossibleValue};
 use itertools::join;
+use serde::Serialize;
 use skim::{self, prelude::*};
+use std::collections::BTreeMap;
 use std::io::Cursor;
 use std::str::FromStr;
 
-// REVIEW doesn't look like this macro supports 2 nested enums
-// Buildable and ProjectConfig (Features, Profiles)
+/// A query target that resolves to a set of buildable `Target`s and knows
+/// which `CompileMode` it should be compiled under.
+trait Buildable {
+    fn as_target_pred(&self) -> fn(&Target) -> bool;
+    fn compile_mode(&self) -> CompileMode;
+}
+
+/// A query target that describes project configuration (what features or
+/// profiles are available) rather than a build target. Unlike `Buildable`,
+/// these never need a `CompileMode` of their own.
+trait ProjectConfig {
+    fn allows_multi(&self) -> bool;
+    fn candidates(&self, ws: &Workspace<'_>, profs: &Profiles) -> CargoResult<Vec<Candidate>>;
+}
+
 #[derive(clap::ArgEnum, Clone, Debug)]
-enum QueryTargets {
+enum BuildTarget {
     // Find all buildable binary executable
     Binaries,
     // Find all examples in the workspace
@@ -23,6 +38,59 @@ enum QueryTargets {
     Tests,
     // Find all benchmark build targets
     Benches,
+}
+
+impl AsRef<str> for BuildTarget {
+    fn as_ref(&self) -> &str {
+        match self {
+            BuildTarget::Binaries => "binaries",
+            BuildTarget::Examples => "examples",
+            BuildTarget::Tests => "tests",
+            BuildTarget::Benches => "benches",
+        }
+    }
+}
+
+impl Buildable for BuildTarget {
+    fn as_target_pred(&self) -> fn(&Target) -> bool {
+        match self {
+            BuildTarget::Binaries => Target::is_bin,
+            BuildTarget::Tests => Target::is_test,
+            BuildTarget::Benches => Target::is_bench,
+            BuildTarget::Examples => Target::is_example,
+        }
+    }
+
+    fn compile_mode(&self) -> CompileMode {
+        match self {
+            // REVIEW this might need to come from another argument to query
+            // eg. cargo build <TAB><TAB> might be different from
+            // cargo run --features <TAB><TAB>
+            BuildTarget::Binaries | BuildTarget::Examples => CompileMode::Build,
+            BuildTarget::Tests => CompileMode::Test,
+            BuildTarget::Benches => CompileMode::Bench,
+        }
+    }
+}
+
+impl BuildTarget {
+    /// The `cargo test`/`cargo build`/... flag that picks out a single
+    /// target by name, e.g. `--bin foo`. `Tests` isn't covered here since
+    /// its candidates come from `get_test_runnables` instead, which already
+    /// knows the right flag for each kind of runnable (`--lib`, `--test`,
+    /// `--doc`).
+    fn invocation_flag(&self, name: &str) -> String {
+        match self {
+            BuildTarget::Binaries => format!("--bin {name}"),
+            BuildTarget::Examples => format!("--example {name}"),
+            BuildTarget::Benches => format!("--bench {name}"),
+            BuildTarget::Tests => format!("--test {name}"),
+        }
+    }
+}
+
+#[derive(clap::ArgEnum, Clone, Debug)]
+enum ConfigTarget {
     // Find all features defined in this workspace to help complete
     // --features <TAB><TAB>
     Features,
@@ -30,23 +98,62 @@ enum QueryTargets {
     Profile,
 }
 
+impl AsRef<str> for ConfigTarget {
+    fn as_ref(&self) -> &str {
+        match self {
+            ConfigTarget::Features => "features",
+            ConfigTarget::Profile => "profile",
+        }
+    }
+}
+
+impl ProjectConfig for ConfigTarget {
+    fn allows_multi(&self) -> bool {
+        matches!(self, ConfigTarget::Features)
+    }
+
+    fn candidates(&self, ws: &Workspace<'_>, profs: &Profiles) -> CargoResult<Vec<Candidate>> {
+        match self {
+            ConfigTarget::Features => get_available_features(ws),
+            ConfigTarget::Profile => get_available_profiles(profs),
+        }
+    }
+}
+
+// clap::ArgEnum's derive macro doesn't support nesting one enum inside
+// another, so `QueryTargets` wraps `BuildTarget`/`ConfigTarget` by hand and
+// implements `FromStr`/`AsRef<str>` itself instead of deriving `ArgEnum`.
+#[derive(Clone, Debug)]
+enum QueryTargets {
+    Build(BuildTarget),
+    Config(ConfigTarget),
+    // Chains a binary picker and a features picker to produce a full
+    // `cargo run` argument string in one pass.
+    RunConfig,
+}
+
+const RUN_CONFIG: &str = "run-config";
+
 impl QueryTargets {
     pub fn possible_values() -> impl Iterator<Item = PossibleValue<'static>> {
-        QueryTargets::value_variants()
+        let build = BuildTarget::value_variants()
+            .iter()
+            .filter_map(ArgEnum::to_possible_value);
+        let config = ConfigTarget::value_variants()
             .iter()
-            .filter_map(ArgEnum::to_possible_value)
+            .filter_map(ArgEnum::to_possible_value);
+        build
+            .chain(config)
+            .chain(std::iter::once(PossibleValue::new(RUN_CONFIG)))
     }
 }
 
 impl AsRef<str> for QueryTargets {
     fn as_ref(&self) -> &str {
         match self {
-            QueryTargets::Binaries => "binaries",
-            QueryTargets::Examples => "examples",
-            QueryTargets::Tests => "tests",
-            QueryTargets::Benches => "benches",
-            QueryTargets::Features => "features",
-            QueryTargets::Profile => "profile",
+            QueryTargets::Build(b) => b.as_ref(),
+            QueryTargets::Config(c) => c.as_ref(),
+            QueryTargets::RunConfig => RUN_CONFIG,
         }
     }
 }
@@ -55,15 +162,16 @@ impl FromStr for QueryTargets {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> CargoResult<Self> {
-        match s {
-            "tests" => Ok(QueryTargets::Tests),
-            "binaries" => Ok(QueryTargets::Binaries),
-            "examples" => Ok(QueryTargets::Examples),
-            "benches" => Ok(QueryTargets::Benches),
-            "features" => Ok(QueryTargets::Features),
-            "profile" => Ok(QueryTargets::Profile),
-            _ => Err(anyhow::format_err!("Unknown type {}", s)),
+        if s.eq_ignore_ascii_case(RUN_CONFIG) {
+            return Ok(QueryTargets::RunConfig);
         }
+        if let Ok(build) = BuildTarget::from_str(s, true) {
+            return Ok(QueryTargets::Build(build));
+        }
+        if let Ok(config) = ConfigTarget::from_str(s, true) {
+            return Ok(QueryTargets::Config(config));
+        }
+        Err(anyhow::format_err!("Unknown type {}", s))
     }
 }
 
@@ -75,6 +183,24 @@ pub fn cli() -> App {
                 .possible_values(QueryTargets::possible_values())
                 .ignore_case(true),
         )
+        .arg(
+            Arg::new("no-interactive")
+                .long("no-interactive")
+                .help("Skip the skim UI and print candidates straight to stdout, for scripts and shell completions"),
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .help("Output format to use with --no-interactive")
+                .possible_values(["json", "plain"])
+                .default_value("plain"),
+        )
+        .arg(
+            Arg::new("exec")
+                .long("exec")
+                .visible_alias("and-run")
+                .help("Spawn the matching cargo run/test/bench invocation for the selection instead of printing it"),
+        )
         .about("List query targets")
         .after_help("Run `cargo help query` for more detailed information.\n")
         // TODO all these below are hacks around the fact that
@@ -99,6 +225,54 @@ pub fn cli() -> App {
         )
 }
 
+/// A single thing `cargo query` can offer the user: a buildable target, a
+/// feature, or a profile. This is the structured form candidates are
+/// produced in; the interactive skim UI flattens it to a display line, and
+/// `--no-interactive` serializes it (as JSON) or prints just the name (as
+/// plain text).
+#[derive(Serialize)]
+struct Candidate {
+    name: String,
+    kind: String,
+    /// For features, the sub-features/dependencies this one turns on
+    /// (`dep:serde`, `chrono/serde`, ...). Always empty for targets/profiles.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    enables: Vec<String>,
+    /// The cargo flags that run/build exactly this candidate, e.g.
+    /// `--bin foo` or `--test integration`. `None` for candidates that
+    /// aren't a target in their own right, like features or profiles.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invocation: Option<String>,
+    /// Source file the candidate is defined in, where that's meaningful
+    /// (targets); `None` for features/profiles and doctests, which aren't
+    /// tied to one file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    module_path: Option<String>,
+}
+
+impl Candidate {
+    fn simple(name: String, kind: &str) -> Self {
+        Candidate {
+            name,
+            kind: kind.to_string(),
+            enables: Vec::new(),
+            invocation: None,
+            module_path: None,
+        }
+    }
+
+    /// The line handed to skim: the name alone, or `name\tname = [...]` when
+    /// there's something to show the user beyond the bare name. Only the
+    /// part before the tab is meant to be pasted back into a cargo flag.
+    fn to_skim_line(&self) -> String {
+        if self.enables.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}\t{} = [{}]", self.name, self.name, join(&self.enables, ", "))
+        }
+    }
+}
+
 type NewLineSeparatedInput = String;
 
 struct MySkimOptions<'a> {
@@ -115,91 +289,284 @@ struct MySkimOptions<'a> {
     abs_height: usize,
 }
 
+fn build_skim_options<'a>(
+    prompt: &'a str,
+    allows_multi: bool,
+    candidates: &[Candidate],
+) -> MySkimOptions<'a> {
+    let lines: Vec<String> = candidates.iter().map(Candidate::to_skim_line).collect();
+    MySkimOptions {
+        abs_height: lines.len() * 3,
+        input: lines.join("\n"),
+        prompt,
+        allows_multi,
+    }
+}
+
 impl QueryTargets {
-    fn as_target_pred(&self) -> fn(&Target) -> bool {
+    /// The candidates this query target offers, regardless of whether
+    /// they'll end up in the interactive skim UI or printed straight to
+    /// stdout for `--no-interactive`.
+    fn candidates(
+        &self,
+        ws: &Workspace<'_>,
+        compile_opts: &CompileOptions,
+    ) -> CargoResult<Vec<Candidate>> {
         match self {
-            QueryTargets::Binaries => Target::is_bin,
-            // REVIEW Target::is_test finds only integration tests, I want to list all test targets
-            // REVIEW rust-analyzer reuse for finding runnables
-            QueryTargets::Tests => Target::is_test,
-            QueryTargets::Benches => Target::is_bench,
-            QueryTargets::Examples => Target::is_example,
-            QueryTargets::Features | QueryTargets::Profile => {
-                unimplemented!(
-                    "You shouldn't be filtering build targets with {:?}",
-                    self.as_ref()
-                )
+            // Unit/integration/doctests live under separate Target kinds
+            // and file layouts, so they get their own discovery pass
+            // instead of a single `as_target_pred` filter.
+            QueryTargets::Build(BuildTarget::Tests) => get_test_runnables(&ws),
+            QueryTargets::Build(b) => {
+                let targets = get_available_targets(b.as_target_pred(), &ws, &compile_opts)?;
+                Ok(targets
+                    .into_iter()
+                    .map(|name| {
+                        let mut candidate = Candidate::simple(name.clone(), b.as_ref());
+                        candidate.invocation = Some(b.invocation_flag(&name));
+                        candidate
+                    })
+                    .collect())
+            }
+            QueryTargets::Config(c) => {
+                // REVIEW can I get all Profiles available in the workspace somehow without passing a requested_profile?
+                // ws.profiles() returned None when I ran it
+                let profs = Profiles::new(ws, compile_opts.build_config.requested_profile)?;
+                c.candidates(ws, &profs)
             }
+            QueryTargets::RunConfig => Err(anyhow::format_err!(
+                "run-config chains an interactive binary picker and a features picker, \
+                 so it has no flat candidate list; query `binaries` or `features` instead for --no-interactive"
+            )),
         }
     }
 
     fn allows_multi(&self) -> bool {
         match self {
-            QueryTargets::Binaries
-            | QueryTargets::Examples
-            | QueryTargets::Tests
-            | QueryTargets::Benches
-            | QueryTargets::Profile => false,
-            QueryTargets::Features => true,
+            QueryTargets::Config(c) => c.allows_multi(),
+            QueryTargets::Build(_) | QueryTargets::RunConfig => false,
         }
     }
-
-    fn make_skim_options(
-        &self,
-        ws: &Workspace<'_>,
-        compile_opts: &CompileOptions,
-    ) -> CargoResult<MySkimOptions<'_>> {
-        // REVIEW can I get all Profiles available in the workspace somehow without passing a requested_profile?
-        // ws.profiles() returned None when I ran it
-        let profs = Profiles::new(ws, compile_opts.build_config.requested_profile)?;
-        let targets = match self {
-            QueryTargets::Binaries
-            | QueryTargets::Examples
-            | QueryTargets::Tests
-            | QueryTargets::Benches => {
-                get_available_targets(self.as_target_pred(), &ws, &compile_opts)?
-            }
-            QueryTargets::Profile => get_available_profiles(&profs)?,
-            QueryTargets::Features => unimplemented!(),
-        };
-
-        // pass string representations of targets to skim
-        Ok(MySkimOptions {
-            input: targets.join("\n"),
-            prompt: self.as_ref(),
-            allows_multi: self.allows_multi(),
-            abs_height: targets.len() * 3,
-        })
-    }
 }
 
 impl From<&QueryTargets> for CompileMode {
     fn from(val: &QueryTargets) -> Self {
         match val {
-            // REVIEW this might need to come from another argument to query
-            // eg. cargo build <TAB><TAB> might be different from
-            // cargo run --features <TAB><TAB>
-            QueryTargets::Binaries | QueryTargets::Examples => CompileMode::Build,
-            QueryTargets::Tests => CompileMode::Test,
-            QueryTargets::Benches => CompileMode::Bench,
-            // HACK will be removed once QueryTargets is split into Buildable and ProjectConfigs
-            QueryTargets::Profile => CompileMode::Build,
-            QueryTargets::Features => unimplemented!(),
+            QueryTargets::Build(b) => b.compile_mode(),
+            // `ArgMatches::compile_options` requires *some* CompileMode even
+            // though ProjectConfig targets don't build anything themselves.
+            QueryTargets::Config(_) | QueryTargets::RunConfig => CompileMode::Build,
         }
     }
 }
 
-fn get_available_profiles<'a>(profs: &'a Profiles) -> CargoResult<Vec<&'a str>> {
-    let res = profs.list_all();
-    Ok(res)
+fn get_available_profiles(profs: &Profiles) -> CargoResult<Vec<Candidate>> {
+    Ok(profs
+        .list_all()
+        .iter()
+        .map(|p| Candidate::simple(p.to_string(), "profile"))
+        .collect())
+}
+
+/// Prefix a feature name with `prefix/` (cargo's own syntax for enabling a
+/// dependency's feature), or leave it bare when `prefix` is empty.
+fn qualify_feature(prefix: &str, name: &str) -> String {
+    if prefix.is_empty() {
+        name.to_string()
+    } else {
+        format!("{prefix}/{name}")
+    }
+}
+
+/// Merge a plain feature-name-to-enables map into `out`, qualifying each
+/// name via `qualify_feature` and adding the implicit `default` feature if
+/// it wasn't declared explicitly. Doesn't overwrite an existing entry under
+/// the same qualified name. Kept independent of `Package`/`Summary` so the
+/// qualification/collision behavior can be unit tested with synthetic maps
+/// rather than a live workspace.
+fn merge_qualified_features(
+    features: &BTreeMap<String, Vec<String>>,
+    prefix: &str,
+    out: &mut BTreeMap<String, Vec<String>>,
+) {
+    for (name, enables) in features {
+        out.entry(qualify_feature(prefix, name))
+            .or_insert_with(|| enables.clone());
+    }
+    out.entry(qualify_feature(prefix, "default"))
+        .or_insert_with(Vec::new);
 }
 
+/// Every feature declared by `pkg`, plus the implicit `default` feature if
+/// the manifest didn't declare one explicitly, recorded into `out` under
+/// `qualify_feature(prefix, name)`. `prefix` is empty for a workspace
+/// member's own features (bare names, ready to paste after `--features`)
+/// and the dependency's name when `pkg` is one of its dependencies — this
+/// keeps two unrelated crates that happen to declare the same feature name
+/// from colliding in `out`.
+fn collect_package_features(pkg: &Package, prefix: &str, out: &mut BTreeMap<String, Vec<String>>) {
+    let features: BTreeMap<String, Vec<String>> = pkg
+        .summary()
+        .features()
+        .iter()
+        .map(|(name, values)| {
+            (
+                name.to_string(),
+                values.iter().map(ToString::to_string).collect(),
+            )
+        })
+        .collect();
+    merge_qualified_features(&features, prefix, out);
+}
+
+/// List every feature reachable from the workspace: features declared
+/// directly on workspace members, plus each member's direct dependencies'
+/// own `[features]` tables qualified as `dep_name/feature`. Walks
+/// `ws.members()` (as `get_test_runnables` already does) rather than the
+/// whole resolved dependency graph, so a feature is only ever attributed to
+/// the package that actually declares it.
+fn get_available_features(ws: &Workspace<'_>) -> CargoResult<Vec<Candidate>> {
+    let (pkg_set, resolve) = cargo::ops::resolve_ws(ws)?;
+
+    let mut features: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for member in ws.members() {
+        collect_package_features(member, "", &mut features);
+
+        for (dep_id, _) in resolve.deps(member.package_id()) {
+            let dep_pkg = pkg_set.get_one(dep_id)?;
+            collect_package_features(dep_pkg, dep_pkg.name().as_str(), &mut features);
+        }
+    }
+
+    Ok(features
+        .into_iter()
+        .map(|(name, enables)| Candidate {
+            name,
+            kind: "features".to_string(),
+            enables,
+            invocation: None,
+            module_path: None,
+        })
+        .collect())
+}
+
+/// The subset of a `Target`'s metadata that decides what (if any) `cargo
+/// test` runnable candidates it contributes. Factored out of
+/// `get_test_runnables` so the kind/invocation mapping below, including the
+/// `test = false`/`doctest = false` gating, can be unit tested against
+/// synthetic targets rather than a live `Workspace`.
+struct TestableTarget<'a> {
+    name: &'a str,
+    is_lib: bool,
+    is_bin: bool,
+    is_test: bool,
+    tested: bool,
+    doctested: bool,
+    src_path: Option<String>,
+}
+
+/// The `Candidate`s a single target contributes to `cargo test` discovery:
+/// none, one, or (for a lib that's both unit- and doc-tested) two. Mirrors
+/// how rust-analyzer builds its runnable list from target metadata rather
+/// than parsing source for `#[test]` functions, so discovery stays at
+/// target granularity (e.g. `--lib` covers every unit test in the library,
+/// not each `#[test]` fn individually), and honors `test = false`/
+/// `doctest = false` independently the same way rust-analyzer's runnable
+/// discovery does, skipping whichever the target opted out of.
+fn test_candidates_for(pkg_name: &str, target: &TestableTarget<'_>) -> Vec<Candidate> {
+    let mut runnables = Vec::new();
+
+    if target.is_lib {
+        if target.tested {
+            runnables.push(Candidate {
+                name: format!("{pkg_name} (lib)"),
+                kind: "unit".to_string(),
+                enables: Vec::new(),
+                invocation: Some("--lib".to_string()),
+                module_path: Some(
+                    target
+                        .src_path
+                        .clone()
+                        .unwrap_or_else(|| pkg_name.to_string()),
+                ),
+            });
+        }
+        if target.doctested {
+            runnables.push(Candidate {
+                name: format!("{pkg_name} (doctests)"),
+                kind: "doc".to_string(),
+                enables: Vec::new(),
+                invocation: Some("--doc".to_string()),
+                module_path: None,
+            });
+        }
+    } else if target.is_bin {
+        if target.tested {
+            runnables.push(Candidate {
+                name: format!("{} (bin)", target.name),
+                kind: "unit".to_string(),
+                enables: Vec::new(),
+                invocation: Some(format!("--bin {}", target.name)),
+                module_path: Some(
+                    target
+                        .src_path
+                        .clone()
+                        .unwrap_or_else(|| target.name.to_string()),
+                ),
+            });
+        }
+    } else if target.is_test && target.tested {
+        runnables.push(Candidate {
+            name: target.name.to_string(),
+            kind: "integration".to_string(),
+            enables: Vec::new(),
+            invocation: Some(format!("--test {}", target.name)),
+            module_path: Some(
+                target
+                    .src_path
+                    .clone()
+                    .unwrap_or_else(|| target.name.to_string()),
+            ),
+        });
+    }
+
+    runnables
+}
+
+/// Enumerate every way `cargo test` can be pointed at this workspace: unit
+/// tests embedded in lib/bin targets, integration test targets under
+/// `tests/`, and doctests. See `test_candidates_for` for the per-target
+/// kind/invocation mapping.
+fn get_test_runnables(ws: &Workspace<'_>) -> CargoResult<Vec<Candidate>> {
+    let mut runnables = Vec::new();
+
+    for pkg in ws.members() {
+        for target in pkg.targets() {
+            let testable = TestableTarget {
+                name: target.name(),
+                is_lib: target.is_lib(),
+                is_bin: target.is_bin(),
+                is_test: target.is_test(),
+                tested: target.tested(),
+                doctested: target.doctested(),
+                src_path: target.src_path().path().map(|p| p.display().to_string()),
+            };
+            runnables.extend(test_candidates_for(&pkg.name().to_string(), &testable));
+        }
+    }
+
+    Ok(runnables)
+}
+
+/// Run the skim UI over an already-fetched candidate list. Takes
+/// `candidates` rather than a `Workspace`/`CompileOptions` pair so callers
+/// that also need the candidates for something else (e.g. `--exec` mapping
+/// a selection back to its invocation flag) fetch them exactly once.
 fn fuzzy_choose(
-    ws: &Workspace<'_>,
-    compile_opts: &CompileOptions,
-    query_target: QueryTargets,
+    query_target: &QueryTargets,
+    candidates: &[Candidate],
 ) -> CargoResult<Vec<Arc<dyn SkimItem>>> {
-    let options = query_target.make_skim_options(ws, compile_opts)?;
+    let options = build_skim_options(query_target.as_ref(), query_target.allows_multi(), candidates);
 
     let abs_height = format!("{}", options.abs_height);
     let full_prompt = format!("Choose {}> ", options.prompt);
@@ -226,7 +593,134 @@ fn fuzzy_choose(
 }
 
 fn convert_selected_items_to_string(items: Vec<Arc<dyn SkimItem>>) -> CargoResult<String> {
-    Ok(join(items.iter().map(|i| i.text()), ","))
+    // Candidates may carry a `<value>\t<description>` pair (see
+    // `Candidate::to_skim_line`); only the part before the tab is meant to
+    // be pasted after a cargo flag like `--features`.
+    Ok(join(
+        items
+            .iter()
+            .map(|i| i.text().split('\t').next().unwrap_or_default().to_string()),
+        ",",
+    ))
+}
+
+/// Chain a binary picker and a features picker into one `cargo run`
+/// argument string, so completions for `cargo run --features <TAB>` can be
+/// driven end-to-end instead of one dimension at a time.
+fn run_config_args(ws: &Workspace<'_>, compile_opts: &CompileOptions) -> CargoResult<String> {
+    let binary_target = QueryTargets::Build(BuildTarget::Binaries);
+    let binary_candidates = binary_target.candidates(ws, compile_opts)?;
+    let binary =
+        convert_selected_items_to_string(fuzzy_choose(&binary_target, &binary_candidates)?)?;
+    // `fuzzy_choose` returns `Ok(vec![])`, not an error, when the user
+    // cancels out of the picker. Bail here instead of emitting a malformed
+    // `--bin ` with no value.
+    if binary.is_empty() {
+        anyhow::bail!("No binary selected, aborting run-config");
+    }
+    let features_target = QueryTargets::Config(ConfigTarget::Features);
+    let features_candidates = features_target.candidates(ws, compile_opts)?;
+    let features =
+        convert_selected_items_to_string(fuzzy_choose(&features_target, &features_candidates)?)?;
+
+    let mut args = format!("--bin {binary}");
+    if !features.is_empty() {
+        args.push_str(&format!(" --features {features}"));
+    }
+    Ok(args)
+}
+
+/// Print candidates straight to stdout, bypassing skim entirely. `json`
+/// emits the full `Candidate` list for completion engines to parse; `plain`
+/// prints one bare name per line.
+fn print_candidates_non_interactive(
+    config: &mut Config,
+    candidates: &[Candidate],
+    format: &str,
+) -> CargoResult<()> {
+    let output = match format {
+        "json" => serde_json::to_string_pretty(candidates)?,
+        _ => join(candidates.iter().map(|c| c.name.as_str()), "\n"),
+    };
+    config.shell().print_ansi_stdout(output.as_bytes())?;
+    Ok(())
+}
+
+/// After the skim selection resolves, spawn the matching cargo subcommand
+/// instead of just printing the selection: `cargo run` for a chosen
+/// binary/example or a `run-config` combo, `cargo test`/`cargo bench` for a
+/// chosen test/bench, with a chosen feature set or profile threaded through
+/// as `--features`/`--profile`.
+fn run_follow_up(
+    config: &Config,
+    query_target: &QueryTargets,
+    candidates: &[Candidate],
+    selection: &str,
+    args: &ArgMatches,
+) -> CargoResult<()> {
+    // Reuses the same CompileMode::from(&QueryTargets) mapping used to pick
+    // a CompileMode for `args.compile_options` above, so `--exec` always
+    // agrees with what was actually queried/compiled.
+    let subcommand = match query_target {
+        QueryTargets::RunConfig => "run",
+        QueryTargets::Config(_) => "build",
+        QueryTargets::Build(_) => match CompileMode::from(query_target) {
+            CompileMode::Test => "test",
+            CompileMode::Bench => "bench",
+            _ => "run",
+        },
+    };
+
+    // Reuse the same cargo binary that's currently running (toolchain/rustup
+    // shim and all) instead of a bare PATH lookup, matching how cargo's own
+    // self-invocation call sites find themselves.
+    let mut cmd = std::process::Command::new(config.cargo_exe()?);
+    cmd.arg(subcommand);
+
+    match query_target {
+        QueryTargets::RunConfig => {
+            cmd.args(selection.split_whitespace());
+        }
+        QueryTargets::Config(ConfigTarget::Features) => {
+            cmd.arg("--features").arg(selection);
+        }
+        QueryTargets::Config(ConfigTarget::Profile) => {
+            cmd.arg("--profile").arg(selection);
+        }
+        QueryTargets::Build(_) => {
+            for name in selection.split(',').filter(|s| !s.is_empty()) {
+                let invocation = candidates
+                    .iter()
+                    .find(|c| c.name == name)
+                    .and_then(|c| c.invocation.as_ref());
+                match invocation {
+                    Some(invocation) => {
+                        cmd.args(invocation.split_whitespace());
+                    }
+                    None => {
+                        cmd.arg(name);
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some(jobs) = args.value_of("jobs") {
+        cmd.arg("--jobs").arg(jobs);
+    }
+    if let Some(message_format) = args.value_of("message-format") {
+        cmd.arg("--message-format").arg(message_format);
+    }
+
+    config
+        .shell()
+        .status("Running", format!("{:?}", cmd))?;
+
+    let status = cmd.status()?;
+    if !status.success() {
+        anyhow::bail!("`cargo {subcommand}` exited with {status}");
+    }
+    Ok(())
 }
 
 pub fn exec(config: &mut Config, args: &ArgMatches) -> CliResult {
@@ -240,11 +734,176 @@ pub fn exec(config: &mut Config, args: &ArgMatches) -> CliResult {
         ProfileChecking::Custom,
     )?;
 
-    if let Ok(it) =
-        convert_selected_items_to_string(fuzzy_choose(&ws, &compile_opts, query_target)?)
-    {
-        config.shell().print_ansi_stdout(it.as_bytes())?
+    if args.is_present("no-interactive") {
+        let format = args.value_of("format").unwrap_or("plain");
+        let candidates = query_target.candidates(&ws, &compile_opts)?;
+        print_candidates_non_interactive(config, &candidates, format)?;
+        return Ok(());
+    }
+
+    let run_after = args.is_present("exec");
+
+    // `RunConfig` has no flat candidate list of its own (it chains two
+    // sub-pickers, each fetching its own candidates inside
+    // `run_config_args`), so `candidates` only comes back non-empty for the
+    // `other` arm below — fetched once and reused both for the skim UI and,
+    // when `--exec` maps the selection back to its invocation flag, by
+    // `run_follow_up`.
+    let (picked, candidates) = match query_target.clone() {
+        QueryTargets::RunConfig => (run_config_args(&ws, &compile_opts), Vec::new()),
+        other => {
+            let candidates = other.candidates(&ws, &compile_opts)?;
+            let picked = convert_selected_items_to_string(fuzzy_choose(&other, &candidates)?);
+            (picked, candidates)
+        }
+    };
+
+    if let Ok(it) = picked {
+        if run_after {
+            // `fuzzy_choose` returns `Ok(vec![])`, not an error, when the
+            // user cancels out of the picker (see its `TODO bring back
+            // proper error handling` comment), so an empty selection isn't
+            // caught by the `Ok`/`Err` match above. `run_config_args`
+            // already guards its own binary sub-picker the same way; do it
+            // here too so Escape can't spawn a bare `cargo run`/`test`/
+            // `bench` the user never asked for.
+            if it.is_empty() {
+                anyhow::bail!("No selection made, aborting --exec");
+            }
+            run_follow_up(config, &query_target, &candidates, &it, args)?;
+        } else {
+            config.shell().print_ansi_stdout(it.as_bytes())?
+        }
     };
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn qualify_feature_leaves_bare_names_untouched() {
+        assert_eq!(qualify_feature("", "serde"), "serde");
+    }
+
+    #[test]
+    fn qualify_feature_prefixes_with_dep_name() {
+        assert_eq!(qualify_feature("tokio", "rt-multi-thread"), "tokio/rt-multi-thread");
+    }
+
+    #[test]
+    fn merge_qualified_features_adds_implicit_default() {
+        let features = BTreeMap::new();
+        let mut out = BTreeMap::new();
+        merge_qualified_features(&features, "", &mut out);
+        assert_eq!(out.get("default"), Some(&Vec::new()));
+    }
+
+    #[test]
+    fn merge_qualified_features_keeps_same_name_from_different_packages_distinct() {
+        let mut a_features = BTreeMap::new();
+        a_features.insert("default".to_string(), vec!["std".to_string()]);
+        let mut b_features = BTreeMap::new();
+        b_features.insert("default".to_string(), vec!["alloc".to_string()]);
+
+        let mut out = BTreeMap::new();
+        // Own member feature: bare name.
+        merge_qualified_features(&a_features, "", &mut out);
+        // A dependency's own feature of the same name: qualified, so it
+        // doesn't clobber or get clobbered by the member's `default`.
+        merge_qualified_features(&b_features, "some-dep", &mut out);
+
+        assert_eq!(out.get("default"), Some(&vec!["std".to_string()]));
+        assert_eq!(out.get("some-dep/default"), Some(&vec!["alloc".to_string()]));
+    }
+
+    #[test]
+    fn merge_qualified_features_does_not_overwrite_existing_entry() {
+        let mut first = BTreeMap::new();
+        first.insert("logging".to_string(), vec!["dep:log".to_string()]);
+        let mut second = BTreeMap::new();
+        second.insert("logging".to_string(), vec!["dep:tracing".to_string()]);
+
+        let mut out = BTreeMap::new();
+        merge_qualified_features(&first, "", &mut out);
+        merge_qualified_features(&second, "", &mut out);
+
+        assert_eq!(out.get("logging"), Some(&vec!["dep:log".to_string()]));
+    }
+
+    fn test_target(
+        name: &str,
+        is_lib: bool,
+        is_bin: bool,
+        is_test: bool,
+        tested: bool,
+        doctested: bool,
+    ) -> TestableTarget<'_> {
+        TestableTarget {
+            name,
+            is_lib,
+            is_bin,
+            is_test,
+            tested,
+            doctested,
+            src_path: Some(format!("src/{name}.rs")),
+        }
+    }
+
+    #[test]
+    fn test_candidates_for_lib_with_tests_and_doctests() {
+        let target = test_target("mylib", true, false, false, true, true);
+        let candidates = test_candidates_for("mylib", &target);
+
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].name, "mylib (lib)");
+        assert_eq!(candidates[0].kind, "unit");
+        assert_eq!(candidates[0].invocation.as_deref(), Some("--lib"));
+        assert_eq!(candidates[1].name, "mylib (doctests)");
+        assert_eq!(candidates[1].kind, "doc");
+        assert_eq!(candidates[1].invocation.as_deref(), Some("--doc"));
+        assert_eq!(candidates[1].module_path, None);
+    }
+
+    #[test]
+    fn test_candidates_for_lib_respects_test_false_and_doctest_false() {
+        let target = test_target("mylib", true, false, false, false, false);
+        assert!(test_candidates_for("mylib", &target).is_empty());
+    }
+
+    #[test]
+    fn test_candidates_for_bin_skips_when_not_tested() {
+        let target = test_target("mybin", false, true, false, false, false);
+        assert!(test_candidates_for("pkg", &target).is_empty());
+    }
+
+    #[test]
+    fn test_candidates_for_bin_maps_to_bin_invocation() {
+        let target = test_target("mybin", false, true, false, true, false);
+        let candidates = test_candidates_for("pkg", &target);
+
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].name, "mybin (bin)");
+        assert_eq!(candidates[0].kind, "unit");
+        assert_eq!(candidates[0].invocation.as_deref(), Some("--bin mybin"));
+    }
+
+    #[test]
+    fn test_candidates_for_integration_test_maps_to_test_invocation() {
+        let target = test_target("smoke", false, false, true, true, false);
+        let candidates = test_candidates_for("pkg", &target);
+
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].name, "smoke");
+        assert_eq!(candidates[0].kind, "integration");
+        assert_eq!(candidates[0].invocation.as_deref(), Some("--test smoke"));
+    }
+
+    #[test]
+    fn test_candidates_for_integration_test_skips_when_not_tested() {
+        let target = test_target("smoke", false, false, true, false, false);
+        assert!(test_candidates_for("pkg", &target).is_empty());
+    }
+}